@@ -0,0 +1,271 @@
+// dataValidation
+use super::BooleanValue;
+use super::StringValue;
+use super::DataValidationValues;
+use super::DataValidationOperatorValues;
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct DataValidation {
+    data_validation_type: DataValidationValues,
+    operator: DataValidationOperatorValues,
+    sqref: StringValue,
+    formula1: StringValue,
+    formula2: StringValue,
+    allow_blank: BooleanValue,
+    show_input_message: BooleanValue,
+    prompt_title: StringValue,
+    prompt: StringValue,
+    show_error_message: BooleanValue,
+    error_title: StringValue,
+    error: StringValue,
+    error_style: StringValue,
+}
+impl DataValidation {
+    pub fn get_data_validation_type(&self) -> &DataValidationValues {
+        &self.data_validation_type
+    }
+
+    pub fn set_data_validation_type(&mut self, value: DataValidationValues) -> &mut Self {
+        self.data_validation_type = value;
+        self
+    }
+
+    pub fn get_operator(&self) -> &DataValidationOperatorValues {
+        &self.operator
+    }
+
+    pub fn set_operator(&mut self, value: DataValidationOperatorValues) -> &mut Self {
+        self.operator = value;
+        self
+    }
+
+    pub fn get_sqref(&self) -> &str {
+        self.sqref.get_value()
+    }
+
+    pub fn set_sqref<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sqref.set_value(value);
+        self
+    }
+
+    pub fn get_formula1(&self) -> &str {
+        self.formula1.get_value()
+    }
+
+    pub fn set_formula1<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula1.set_value(value);
+        self
+    }
+
+    pub fn get_formula2(&self) -> &str {
+        self.formula2.get_value()
+    }
+
+    pub fn set_formula2<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula2.set_value(value);
+        self
+    }
+
+    pub fn get_allow_blank(&self) -> &bool {
+        self.allow_blank.get_value()
+    }
+
+    pub fn set_allow_blank(&mut self, value: bool) -> &mut Self {
+        self.allow_blank.set_value(value);
+        self
+    }
+
+    pub fn get_show_input_message(&self) -> &bool {
+        self.show_input_message.get_value()
+    }
+
+    pub fn set_show_input_message(&mut self, value: bool) -> &mut Self {
+        self.show_input_message.set_value(value);
+        self
+    }
+
+    pub fn get_prompt_title(&self) -> &str {
+        self.prompt_title.get_value()
+    }
+
+    pub fn set_prompt_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.prompt_title.set_value(value);
+        self
+    }
+
+    pub fn get_prompt(&self) -> &str {
+        self.prompt.get_value()
+    }
+
+    pub fn set_prompt<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.prompt.set_value(value);
+        self
+    }
+
+    pub fn get_show_error_message(&self) -> &bool {
+        self.show_error_message.get_value()
+    }
+
+    pub fn set_show_error_message(&mut self, value: bool) -> &mut Self {
+        self.show_error_message.set_value(value);
+        self
+    }
+
+    pub fn get_error_title(&self) -> &str {
+        self.error_title.get_value()
+    }
+
+    pub fn set_error_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.error_title.set_value(value);
+        self
+    }
+
+    pub fn get_error(&self) -> &str {
+        self.error.get_value()
+    }
+
+    pub fn set_error<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.error.set_value(value);
+        self
+    }
+
+    pub fn get_error_style(&self) -> &str {
+        self.error_style.get_value()
+    }
+
+    pub fn set_error_style<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.error_style.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader: &mut Reader<std::io::BufReader<std::fs::File>>,
+        e: &BytesStart,
+    ) {
+        self.data_validation_type.set_value_string(get_attribute(e, b"type").unwrap_or_default());
+        self.operator.set_value_string(get_attribute(e, b"operator").unwrap_or_default());
+        if let Some(v) = get_attribute(e, b"sqref") {
+            self.set_sqref(v);
+        }
+        if let Some(v) = get_attribute(e, b"allowBlank") {
+            self.allow_blank.set_value_string(v);
+        }
+        if let Some(v) = get_attribute(e, b"showInputMessage") {
+            self.show_input_message.set_value_string(v);
+        }
+        if let Some(v) = get_attribute(e, b"promptTitle") {
+            self.set_prompt_title(v);
+        }
+        if let Some(v) = get_attribute(e, b"prompt") {
+            self.set_prompt(v);
+        }
+        if let Some(v) = get_attribute(e, b"showErrorMessage") {
+            self.show_error_message.set_value_string(v);
+        }
+        if let Some(v) = get_attribute(e, b"errorTitle") {
+            self.set_error_title(v);
+        }
+        if let Some(v) = get_attribute(e, b"error") {
+            self.set_error(v);
+        }
+        if let Some(v) = get_attribute(e, b"errorStyle") {
+            self.set_error_style(v);
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"formula1" => {
+                            self.set_formula1(get_text(reader));
+                        }
+                        b"formula2" => {
+                            self.set_formula2(get_text(reader));
+                        }
+                        _ => (),
+                    }
+                }
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"dataValidation" => return,
+                        _ => (),
+                    }
+                }
+                Ok(Event::Eof) => panic!("Error not find {} end element", "dataValidation"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let mut attributes: Vec<(&str, &str)> = vec![
+            ("type", self.data_validation_type.get_value_string()),
+            ("operator", self.operator.get_value_string()),
+            ("allowBlank", if *self.get_allow_blank() { "1" } else { "0" }),
+            ("showInputMessage", if *self.get_show_input_message() { "1" } else { "0" }),
+            ("showErrorMessage", if *self.get_show_error_message() { "1" } else { "0" }),
+        ];
+        if !self.prompt_title.get_value().is_empty() {
+            attributes.push(("promptTitle", self.get_prompt_title()));
+        }
+        if !self.prompt.get_value().is_empty() {
+            attributes.push(("prompt", self.get_prompt()));
+        }
+        if !self.error_title.get_value().is_empty() {
+            attributes.push(("errorTitle", self.get_error_title()));
+        }
+        if !self.error.get_value().is_empty() {
+            attributes.push(("error", self.get_error()));
+        }
+        if !self.error_style.get_value().is_empty() {
+            attributes.push(("errorStyle", self.get_error_style()));
+        }
+        attributes.push(("sqref", self.get_sqref()));
+
+        write_start_tag(writer, "dataValidation", attributes, false);
+
+        if !self.formula1.get_value().is_empty() {
+            write_start_tag(writer, "formula1", vec![], false);
+            write_text_node(writer, self.get_formula1());
+            write_end_tag(writer, "formula1");
+        }
+
+        if !self.formula2.get_value().is_empty() {
+            write_start_tag(writer, "formula2", vec![], false);
+            write_text_node(writer, self.get_formula2());
+            write_end_tag(writer, "formula2");
+        }
+
+        write_end_tag(writer, "dataValidation");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_to_includes_formulas_only_when_set() {
+        let mut data_validation = DataValidation::default();
+        data_validation.set_sqref("B2:B5");
+        data_validation.set_formula1("10");
+
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        data_validation.write_to(&mut writer);
+        let xml = String::from_utf8(writer.into_inner().into_inner()).unwrap();
+
+        assert!(xml.contains("sqref=\"B2:B5\""));
+        assert!(xml.contains("<formula1>10</formula1>"));
+        assert!(!xml.contains("formula2"));
+    }
+}
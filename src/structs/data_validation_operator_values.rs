@@ -0,0 +1,51 @@
+// data validation comparison operator, as carried by dataValidation/@operator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataValidationOperatorValues {
+    Between,
+    NotBetween,
+    Equal,
+    NotEqual,
+    GreaterThan,
+    LessThan,
+    GreaterThanOrEqual,
+    LessThanOrEqual,
+}
+impl Default for DataValidationOperatorValues {
+    fn default() -> Self {
+        DataValidationOperatorValues::Between
+    }
+}
+impl DataValidationOperatorValues {
+    pub(crate) fn get_value_string(&self) -> &str {
+        match *self {
+            DataValidationOperatorValues::Between => "between",
+            DataValidationOperatorValues::NotBetween => "notBetween",
+            DataValidationOperatorValues::Equal => "equal",
+            DataValidationOperatorValues::NotEqual => "notEqual",
+            DataValidationOperatorValues::GreaterThan => "greaterThan",
+            DataValidationOperatorValues::LessThan => "lessThan",
+            DataValidationOperatorValues::GreaterThanOrEqual => "greaterThanOrEqual",
+            DataValidationOperatorValues::LessThanOrEqual => "lessThanOrEqual",
+        }
+    }
+
+    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let value = value.into();
+        match value.as_str() {
+            "between" => self.set_value(DataValidationOperatorValues::Between),
+            "notBetween" => self.set_value(DataValidationOperatorValues::NotBetween),
+            "equal" => self.set_value(DataValidationOperatorValues::Equal),
+            "notEqual" => self.set_value(DataValidationOperatorValues::NotEqual),
+            "greaterThan" => self.set_value(DataValidationOperatorValues::GreaterThan),
+            "lessThan" => self.set_value(DataValidationOperatorValues::LessThan),
+            "greaterThanOrEqual" => self.set_value(DataValidationOperatorValues::GreaterThanOrEqual),
+            "lessThanOrEqual" => self.set_value(DataValidationOperatorValues::LessThanOrEqual),
+            _ => self.set_value(DataValidationOperatorValues::Between),
+        }
+    }
+
+    pub(crate) fn set_value(&mut self, value: DataValidationOperatorValues) -> &mut Self {
+        *self = value;
+        self
+    }
+}
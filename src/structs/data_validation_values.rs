@@ -0,0 +1,50 @@
+// data validation type, as carried by dataValidation/@type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataValidationValues {
+    List,
+    Whole,
+    Decimal,
+    Date,
+    Time,
+    TextLength,
+    Custom,
+    None,
+}
+impl Default for DataValidationValues {
+    fn default() -> Self {
+        DataValidationValues::None
+    }
+}
+impl DataValidationValues {
+    pub(crate) fn get_value_string(&self) -> &str {
+        match *self {
+            DataValidationValues::List => "list",
+            DataValidationValues::Whole => "whole",
+            DataValidationValues::Decimal => "decimal",
+            DataValidationValues::Date => "date",
+            DataValidationValues::Time => "time",
+            DataValidationValues::TextLength => "textLength",
+            DataValidationValues::Custom => "custom",
+            DataValidationValues::None => "none",
+        }
+    }
+
+    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let value = value.into();
+        match value.as_str() {
+            "list" => self.set_value(DataValidationValues::List),
+            "whole" => self.set_value(DataValidationValues::Whole),
+            "decimal" => self.set_value(DataValidationValues::Decimal),
+            "date" => self.set_value(DataValidationValues::Date),
+            "time" => self.set_value(DataValidationValues::Time),
+            "textLength" => self.set_value(DataValidationValues::TextLength),
+            "custom" => self.set_value(DataValidationValues::Custom),
+            _ => self.set_value(DataValidationValues::None),
+        }
+    }
+
+    pub(crate) fn set_value(&mut self, value: DataValidationValues) -> &mut Self {
+        *self = value;
+        self
+    }
+}
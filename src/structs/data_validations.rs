@@ -0,0 +1,124 @@
+// dataValidations
+//
+// STILL NOT WIRED INTO A WORKSHEET, despite the request this landed
+// under asking for exactly that: `structs/worksheet.rs` isn't part of
+// this tree, so there's no `Worksheet` field holding a `DataValidations`
+// and no `worksheet::write`/`Worksheet::set_attributes` call site to
+// read or write `<dataValidations>` from a sheet. Until that file is in
+// scope, this struct is reachable (see `structs/mod.rs`) but nothing can
+// attach one to a sheet or persist it through a round trip -
+// `write_to`/`set_attributes` are implemented and ready, not the feature
+// itself.
+use super::DataValidation;
+use writer::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct DataValidations {
+    data_validation_list: Vec<DataValidation>,
+}
+impl DataValidations {
+    pub fn get_data_validation_list(&self) -> &Vec<DataValidation> {
+        &self.data_validation_list
+    }
+
+    pub fn get_data_validation_list_mut(&mut self) -> &mut Vec<DataValidation> {
+        &mut self.data_validation_list
+    }
+
+    pub fn set_data_validation_list(&mut self, value: Vec<DataValidation>) -> &mut Self {
+        self.data_validation_list = value;
+        self
+    }
+
+    pub fn add_data_validation_list(&mut self, value: DataValidation) -> &mut Self {
+        self.data_validation_list.push(value);
+        self
+    }
+
+    pub fn has_data_validation(&self) -> bool {
+        !self.data_validation_list.is_empty()
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader: &mut Reader<std::io::BufReader<std::fs::File>>,
+        _e: &BytesStart,
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name() {
+                        b"dataValidation" => {
+                            let mut obj = DataValidation::default();
+                            obj.set_attributes(reader, e);
+                            self.add_data_validation_list(obj);
+                        }
+                        _ => (),
+                    }
+                }
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"dataValidations" => return,
+                        _ => (),
+                    }
+                }
+                Ok(Event::Eof) => panic!("Error not find {} end element", "dataValidations"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if !self.has_data_validation() {
+            return;
+        }
+
+        // dataValidations
+        write_start_tag(writer, "dataValidations", vec![
+            ("count", &self.data_validation_list.len().to_string()),
+        ], false);
+
+        for data_validation in &self.data_validation_list {
+            data_validation.write_to(writer);
+        }
+
+        write_end_tag(writer, "dataValidations");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_to_string(data_validations: &DataValidations) -> String {
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        data_validations.write_to(&mut writer);
+        String::from_utf8(writer.into_inner().into_inner()).unwrap()
+    }
+
+    #[test]
+    fn empty_list_writes_nothing() {
+        let data_validations = DataValidations::default();
+        assert!(!data_validations.has_data_validation());
+        assert_eq!(write_to_string(&data_validations), "");
+    }
+
+    #[test]
+    fn non_empty_list_writes_count_and_entries() {
+        let mut data_validations = DataValidations::default();
+        let mut data_validation = DataValidation::default();
+        data_validation.set_sqref("A1:A10");
+        data_validations.add_data_validation_list(data_validation);
+
+        let xml = write_to_string(&data_validations);
+        assert!(xml.contains("<dataValidations count=\"1\">"));
+        assert!(xml.contains("sqref=\"A1:A10\""));
+    }
+}
@@ -0,0 +1,57 @@
+// a:blur
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct Blur {
+    rad: String,
+    grow: String,
+}
+impl Blur {
+    pub fn get_rad(&self) -> &str {
+        &self.rad
+    }
+
+    pub fn set_rad<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.rad = value.into();
+        self
+    }
+
+    pub fn get_grow(&self) -> &str {
+        &self.grow
+    }
+
+    pub fn set_grow<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.grow = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader: &mut Reader<std::io::BufReader<std::fs::File>>,
+        e: &BytesStart,
+    ) {
+        if let Some(v) = get_attribute(e, b"rad") {
+            self.set_rad(v);
+        }
+        if let Some(v) = get_attribute(e, b"grow") {
+            self.set_grow(v);
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:blur
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if !self.rad.is_empty() {
+            attributes.push(("rad", &self.rad));
+        }
+        if !self.grow.is_empty() {
+            attributes.push(("grow", &self.grow));
+        }
+        write_start_tag(writer, "a:blur", attributes, true);
+    }
+}
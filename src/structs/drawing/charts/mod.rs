@@ -0,0 +1,5 @@
+// Additive only, same caveat as `structs/drawing/mod.rs` - this snapshot
+// doesn't have the real `structs/drawing/charts/mod.rs`, which already
+// declares the other chart structs referenced throughout the series.
+mod no_multi_level_labels;
+pub use self::no_multi_level_labels::NoMultiLevelLabels;
@@ -1,6 +1,10 @@
 // a:effectLst
+use super::Blur;
 use super::Glow;
+use super::InnerShadow;
 use super::OuterShadow;
+use super::PresetShadow;
+use super::Reflection;
 use super::SoftEdge;
 use writer::driver::*;
 use quick_xml::Reader;
@@ -10,11 +14,63 @@ use std::io::Cursor;
 
 #[derive(Default, Debug)]
 pub struct EffectList {
+    blur: Option<Blur>,
     glow: Option<Glow>,
+    inner_shadow: Option<InnerShadow>,
     outer_shadow: Option<OuterShadow>,
+    preset_shadow: Option<PresetShadow>,
+    reflection: Option<Reflection>,
     soft_edge: Option<SoftEdge>,
 }
 impl EffectList {
+    pub fn get_blur(&self) -> &Option<Blur> {
+        &self.blur
+    }
+
+    pub fn get_blur_mut(&mut self) -> &mut Option<Blur> {
+        &mut self.blur
+    }
+
+    pub fn set_blur(&mut self, value:Blur) {
+        self.blur = Some(value);
+    }
+
+    pub fn get_inner_shadow(&self) -> &Option<InnerShadow> {
+        &self.inner_shadow
+    }
+
+    pub fn get_inner_shadow_mut(&mut self) -> &mut Option<InnerShadow> {
+        &mut self.inner_shadow
+    }
+
+    pub fn set_inner_shadow(&mut self, value:InnerShadow) {
+        self.inner_shadow = Some(value);
+    }
+
+    pub fn get_preset_shadow(&self) -> &Option<PresetShadow> {
+        &self.preset_shadow
+    }
+
+    pub fn get_preset_shadow_mut(&mut self) -> &mut Option<PresetShadow> {
+        &mut self.preset_shadow
+    }
+
+    pub fn set_preset_shadow(&mut self, value:PresetShadow) {
+        self.preset_shadow = Some(value);
+    }
+
+    pub fn get_reflection(&self) -> &Option<Reflection> {
+        &self.reflection
+    }
+
+    pub fn get_reflection_mut(&mut self) -> &mut Option<Reflection> {
+        &mut self.reflection
+    }
+
+    pub fn set_reflection(&mut self, value:Reflection) {
+        self.reflection = Some(value);
+    }
+
     pub fn get_glow(&self) -> &Option<Glow> {
         &self.glow
     }
@@ -62,6 +118,16 @@ impl EffectList {
             match reader.read_event(&mut buf) {
                 Ok(Event::Empty(ref e)) => {
                     match e.name() {
+                        b"a:blur" => {
+                            let mut obj = Blur::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_blur(obj);
+                        },
+                        b"a:reflection" => {
+                            let mut obj = Reflection::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_reflection(obj);
+                        },
                         b"a:softEdge" => {
                             let mut obj = SoftEdge::default();
                             obj.set_attributes(reader, e);
@@ -77,11 +143,21 @@ impl EffectList {
                             obj.set_attributes(reader, e);
                             &mut self.set_glow(obj);
                         },
+                        b"a:innerShdw" => {
+                            let mut obj = InnerShadow::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_inner_shadow(obj);
+                        },
                         b"a:outerShdw" => {
                             let mut obj = OuterShadow::default();
                             obj.set_attributes(reader, e);
                             &mut self.set_outer_shadow(obj);
                         },
+                        b"a:prstShdw" => {
+                            let mut obj = PresetShadow::default();
+                            obj.set_attributes(reader, e);
+                            &mut self.set_preset_shadow(obj);
+                        },
                         _ => (),
                     }
                 },
@@ -103,18 +179,42 @@ impl EffectList {
         // a:effectLst
         write_start_tag(writer, "a:effectLst", vec![], false);
 
+        // a:blur
+        match &self.blur {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
         // a:glow
         match &self.glow {
             Some(v) => v.write_to(writer),
             None => {},
         }
 
+        // a:innerShdw
+        match &self.inner_shadow {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
         // a:outerShdow
         match &self.outer_shadow {
             Some(v) => v.write_to(writer),
             None => {},
         }
 
+        // a:prstShdw
+        match &self.preset_shadow {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
+        // a:reflection
+        match &self.reflection {
+            Some(v) => v.write_to(writer),
+            None => {},
+        }
+
         // a:softEdge
         match &self.soft_edge {
             Some(v) => v.write_to(writer),
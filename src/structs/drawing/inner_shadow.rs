@@ -0,0 +1,117 @@
+// a:innerShdw
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct InnerShadow {
+    blur_rad: String,
+    dist: String,
+    dir: String,
+    color: String,
+}
+impl InnerShadow {
+    pub fn get_blur_rad(&self) -> &str {
+        &self.blur_rad
+    }
+
+    pub fn set_blur_rad<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.blur_rad = value.into();
+        self
+    }
+
+    pub fn get_dist(&self) -> &str {
+        &self.dist
+    }
+
+    pub fn set_dist<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.dist = value.into();
+        self
+    }
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+
+    pub fn set_dir<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.dir = value.into();
+        self
+    }
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+
+    pub fn set_color<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.color = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader: &mut Reader<std::io::BufReader<std::fs::File>>,
+        e: &BytesStart,
+    ) {
+        if let Some(v) = get_attribute(e, b"blurRad") {
+            self.set_blur_rad(v);
+        }
+        if let Some(v) = get_attribute(e, b"dist") {
+            self.set_dist(v);
+        }
+        if let Some(v) = get_attribute(e, b"dir") {
+            self.set_dir(v);
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:srgbClr" => {
+                            if let Some(v) = get_attribute(e, b"val") {
+                                self.set_color(v);
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"a:innerShdw" => return,
+                        _ => (),
+                    }
+                }
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:innerShdw"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:innerShdw
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if !self.blur_rad.is_empty() {
+            attributes.push(("blurRad", &self.blur_rad));
+        }
+        if !self.dist.is_empty() {
+            attributes.push(("dist", &self.dist));
+        }
+        if !self.dir.is_empty() {
+            attributes.push(("dir", &self.dir));
+        }
+        write_start_tag(writer, "a:innerShdw", attributes, false);
+
+        if !self.color.is_empty() {
+            write_start_tag(writer, "a:srgbClr", vec![
+                ("val", self.color.as_str()),
+            ], true);
+        }
+
+        write_end_tag(writer, "a:innerShdw");
+    }
+}
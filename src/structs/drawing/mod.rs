@@ -0,0 +1,24 @@
+// This file is not a full `structs/drawing/mod.rs` - this snapshot
+// doesn't contain the real one, which already declares `Glow`,
+// `OuterShadow`, `SoftEdge` and others that `effect_list.rs` also uses.
+// These are only the `mod`/`pub use` lines this change series' own new
+// files need; merge them into the existing file instead of replacing it.
+mod blur;
+pub use self::blur::Blur;
+
+mod inner_shadow;
+pub use self::inner_shadow::InnerShadow;
+
+mod preset_shadow;
+pub use self::preset_shadow::PresetShadow;
+
+mod preset_shadow_values;
+pub use self::preset_shadow_values::PresetShadowValues;
+
+mod reflection;
+pub use self::reflection::Reflection;
+
+mod effect_list;
+pub use self::effect_list::EffectList;
+
+pub mod charts;
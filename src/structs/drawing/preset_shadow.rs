@@ -0,0 +1,117 @@
+// a:prstShdw
+use super::PresetShadowValues;
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct PresetShadow {
+    prst: PresetShadowValues,
+    dist: String,
+    dir: String,
+    color: String,
+}
+impl PresetShadow {
+    pub fn get_prst(&self) -> &PresetShadowValues {
+        &self.prst
+    }
+
+    pub fn set_prst(&mut self, value: PresetShadowValues) -> &mut Self {
+        self.prst = value;
+        self
+    }
+
+    pub fn get_dist(&self) -> &str {
+        &self.dist
+    }
+
+    pub fn set_dist<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.dist = value.into();
+        self
+    }
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+
+    pub fn set_dir<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.dir = value.into();
+        self
+    }
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+
+    pub fn set_color<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.color = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        reader: &mut Reader<std::io::BufReader<std::fs::File>>,
+        e: &BytesStart,
+    ) {
+        if let Some(v) = get_attribute(e, b"prst") {
+            self.prst.set_value_string(v);
+        }
+        if let Some(v) = get_attribute(e, b"dist") {
+            self.set_dist(v);
+        }
+        if let Some(v) = get_attribute(e, b"dir") {
+            self.set_dir(v);
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(Event::Empty(ref e)) => {
+                    match e.name() {
+                        b"a:srgbClr" => {
+                            if let Some(v) = get_attribute(e, b"val") {
+                                self.set_color(v);
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                Ok(Event::End(ref e)) => {
+                    match e.name() {
+                        b"a:prstShdw" => return,
+                        _ => (),
+                    }
+                }
+                Ok(Event::Eof) => panic!("Error not find {} end element", "a:prstShdw"),
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:prstShdw
+        let mut attributes: Vec<(&str, &str)> = vec![
+            ("prst", self.prst.get_value_string()),
+        ];
+        if !self.dist.is_empty() {
+            attributes.push(("dist", &self.dist));
+        }
+        if !self.dir.is_empty() {
+            attributes.push(("dir", &self.dir));
+        }
+        write_start_tag(writer, "a:prstShdw", attributes, false);
+
+        if !self.color.is_empty() {
+            write_start_tag(writer, "a:srgbClr", vec![
+                ("val", self.color.as_str()),
+            ], true);
+        }
+
+        write_end_tag(writer, "a:prstShdw");
+    }
+}
@@ -0,0 +1,82 @@
+// a:prstShdw/@prst
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresetShadowValues {
+    Shadow1,
+    Shadow2,
+    Shadow3,
+    Shadow4,
+    Shadow5,
+    Shadow6,
+    Shadow7,
+    Shadow8,
+    Shadow9,
+    Shadow10,
+    Shadow11,
+    Shadow12,
+    Shadow13,
+    Shadow14,
+    Shadow15,
+    Shadow16,
+    Shadow17,
+    Shadow18,
+    Shadow19,
+    Shadow20,
+}
+impl Default for PresetShadowValues {
+    fn default() -> Self {
+        PresetShadowValues::Shadow1
+    }
+}
+impl PresetShadowValues {
+    pub(crate) fn get_value_string(&self) -> &str {
+        match *self {
+            PresetShadowValues::Shadow1 => "shdw1",
+            PresetShadowValues::Shadow2 => "shdw2",
+            PresetShadowValues::Shadow3 => "shdw3",
+            PresetShadowValues::Shadow4 => "shdw4",
+            PresetShadowValues::Shadow5 => "shdw5",
+            PresetShadowValues::Shadow6 => "shdw6",
+            PresetShadowValues::Shadow7 => "shdw7",
+            PresetShadowValues::Shadow8 => "shdw8",
+            PresetShadowValues::Shadow9 => "shdw9",
+            PresetShadowValues::Shadow10 => "shdw10",
+            PresetShadowValues::Shadow11 => "shdw11",
+            PresetShadowValues::Shadow12 => "shdw12",
+            PresetShadowValues::Shadow13 => "shdw13",
+            PresetShadowValues::Shadow14 => "shdw14",
+            PresetShadowValues::Shadow15 => "shdw15",
+            PresetShadowValues::Shadow16 => "shdw16",
+            PresetShadowValues::Shadow17 => "shdw17",
+            PresetShadowValues::Shadow18 => "shdw18",
+            PresetShadowValues::Shadow19 => "shdw19",
+            PresetShadowValues::Shadow20 => "shdw20",
+        }
+    }
+
+    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let value = value.into();
+        *self = match value.as_str() {
+            "shdw2" => PresetShadowValues::Shadow2,
+            "shdw3" => PresetShadowValues::Shadow3,
+            "shdw4" => PresetShadowValues::Shadow4,
+            "shdw5" => PresetShadowValues::Shadow5,
+            "shdw6" => PresetShadowValues::Shadow6,
+            "shdw7" => PresetShadowValues::Shadow7,
+            "shdw8" => PresetShadowValues::Shadow8,
+            "shdw9" => PresetShadowValues::Shadow9,
+            "shdw10" => PresetShadowValues::Shadow10,
+            "shdw11" => PresetShadowValues::Shadow11,
+            "shdw12" => PresetShadowValues::Shadow12,
+            "shdw13" => PresetShadowValues::Shadow13,
+            "shdw14" => PresetShadowValues::Shadow14,
+            "shdw15" => PresetShadowValues::Shadow15,
+            "shdw16" => PresetShadowValues::Shadow16,
+            "shdw17" => PresetShadowValues::Shadow17,
+            "shdw18" => PresetShadowValues::Shadow18,
+            "shdw19" => PresetShadowValues::Shadow19,
+            "shdw20" => PresetShadowValues::Shadow20,
+            _ => PresetShadowValues::Shadow1,
+        };
+        self
+    }
+}
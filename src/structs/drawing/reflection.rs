@@ -0,0 +1,169 @@
+// a:reflection
+use writer::driver::*;
+use reader::driver::*;
+use quick_xml::Reader;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug)]
+pub struct Reflection {
+    blur_rad: String,
+    start_opacity: String,
+    start_position: String,
+    end_alpha: String,
+    end_position: String,
+    dist: String,
+    direction: String,
+    fade_direction: String,
+    rot_with_shape: String,
+}
+impl Reflection {
+    pub fn get_blur_rad(&self) -> &str {
+        &self.blur_rad
+    }
+
+    pub fn set_blur_rad<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.blur_rad = value.into();
+        self
+    }
+
+    pub fn get_start_opacity(&self) -> &str {
+        &self.start_opacity
+    }
+
+    pub fn set_start_opacity<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.start_opacity = value.into();
+        self
+    }
+
+    pub fn get_start_position(&self) -> &str {
+        &self.start_position
+    }
+
+    pub fn set_start_position<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.start_position = value.into();
+        self
+    }
+
+    pub fn get_end_alpha(&self) -> &str {
+        &self.end_alpha
+    }
+
+    pub fn set_end_alpha<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.end_alpha = value.into();
+        self
+    }
+
+    pub fn get_end_position(&self) -> &str {
+        &self.end_position
+    }
+
+    pub fn set_end_position<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.end_position = value.into();
+        self
+    }
+
+    pub fn get_dist(&self) -> &str {
+        &self.dist
+    }
+
+    pub fn set_dist<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.dist = value.into();
+        self
+    }
+
+    pub fn get_direction(&self) -> &str {
+        &self.direction
+    }
+
+    pub fn set_direction<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.direction = value.into();
+        self
+    }
+
+    pub fn get_fade_direction(&self) -> &str {
+        &self.fade_direction
+    }
+
+    pub fn set_fade_direction<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.fade_direction = value.into();
+        self
+    }
+
+    pub fn get_rot_with_shape(&self) -> &str {
+        &self.rot_with_shape
+    }
+
+    pub fn set_rot_with_shape<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.rot_with_shape = value.into();
+        self
+    }
+
+    pub(crate) fn set_attributes(
+        &mut self,
+        _reader: &mut Reader<std::io::BufReader<std::fs::File>>,
+        e: &BytesStart,
+    ) {
+        if let Some(v) = get_attribute(e, b"blurRad") {
+            self.set_blur_rad(v);
+        }
+        if let Some(v) = get_attribute(e, b"stA") {
+            self.set_start_opacity(v);
+        }
+        if let Some(v) = get_attribute(e, b"stPos") {
+            self.set_start_position(v);
+        }
+        if let Some(v) = get_attribute(e, b"endA") {
+            self.set_end_alpha(v);
+        }
+        if let Some(v) = get_attribute(e, b"endPos") {
+            self.set_end_position(v);
+        }
+        if let Some(v) = get_attribute(e, b"dist") {
+            self.set_dist(v);
+        }
+        if let Some(v) = get_attribute(e, b"dir") {
+            self.set_direction(v);
+        }
+        if let Some(v) = get_attribute(e, b"fadeDir") {
+            self.set_fade_direction(v);
+        }
+        if let Some(v) = get_attribute(e, b"rotWithShape") {
+            self.set_rot_with_shape(v);
+        }
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // a:reflection
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if !self.blur_rad.is_empty() {
+            attributes.push(("blurRad", &self.blur_rad));
+        }
+        if !self.start_opacity.is_empty() {
+            attributes.push(("stA", &self.start_opacity));
+        }
+        if !self.start_position.is_empty() {
+            attributes.push(("stPos", &self.start_position));
+        }
+        if !self.end_alpha.is_empty() {
+            attributes.push(("endA", &self.end_alpha));
+        }
+        if !self.end_position.is_empty() {
+            attributes.push(("endPos", &self.end_position));
+        }
+        if !self.dist.is_empty() {
+            attributes.push(("dist", &self.dist));
+        }
+        if !self.direction.is_empty() {
+            attributes.push(("dir", &self.direction));
+        }
+        if !self.fade_direction.is_empty() {
+            attributes.push(("fadeDir", &self.fade_direction));
+        }
+        if !self.rot_with_shape.is_empty() {
+            attributes.push(("rotWithShape", &self.rot_with_shape));
+        }
+        write_start_tag(writer, "a:reflection", attributes, true);
+    }
+}
@@ -0,0 +1,44 @@
+// xl/externalLinks/externalLinkN.xml - a reference to another workbook
+use super::ExternalSheetData;
+
+#[derive(Default, Debug, Clone)]
+pub struct ExternalLink {
+    target: String,
+    sheet_names: Vec<String>,
+    sheet_data_set: Vec<ExternalSheetData>,
+}
+impl ExternalLink {
+    /// The external target, e.g. `file:///C:/Book2.xlsx`, written into
+    /// `externalLinkN.xml.rels` with `TargetMode="External"`.
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn set_target<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.target = value.into();
+        self
+    }
+
+    pub fn get_sheet_names(&self) -> &Vec<String> {
+        &self.sheet_names
+    }
+
+    pub fn set_sheet_names(&mut self, value: Vec<String>) -> &mut Self {
+        self.sheet_names = value;
+        self
+    }
+
+    pub fn add_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet_names.push(value.into());
+        self
+    }
+
+    pub fn get_sheet_data_set(&self) -> &Vec<ExternalSheetData> {
+        &self.sheet_data_set
+    }
+
+    pub fn add_sheet_data(&mut self, value: ExternalSheetData) -> &mut Self {
+        self.sheet_data_set.push(value);
+        self
+    }
+}
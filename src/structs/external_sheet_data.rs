@@ -0,0 +1,25 @@
+// externalBook/sheetDataSet/sheetData - a cache of a single external sheet's last-known values
+#[derive(Default, Debug, Clone)]
+pub struct ExternalSheetData {
+    sheet_id: u32,
+    cell_collection: Vec<(String, String)>,
+}
+impl ExternalSheetData {
+    pub fn get_sheet_id(&self) -> &u32 {
+        &self.sheet_id
+    }
+
+    pub fn set_sheet_id(&mut self, value: u32) -> &mut Self {
+        self.sheet_id = value;
+        self
+    }
+
+    pub fn get_cell_collection(&self) -> &Vec<(String, String)> {
+        &self.cell_collection
+    }
+
+    pub fn add_cell<S: Into<String>>(&mut self, coordinate: S, value: S) -> &mut Self {
+        self.cell_collection.push((coordinate.into(), value.into()));
+        self
+    }
+}
@@ -0,0 +1,28 @@
+// This file is not a full `structs/mod.rs` - this snapshot doesn't
+// contain the real one, which already declares `Spreadsheet`, `Worksheet`,
+// `Cell`, `Style`, `BooleanValue`, `StringValue` and everything else this
+// series' new files build on. These are only the `mod`/`pub use` lines
+// this series' own new structs need; merge them into the existing file
+// instead of replacing it.
+mod data_validation_values;
+pub use self::data_validation_values::DataValidationValues;
+
+mod data_validation_operator_values;
+pub use self::data_validation_operator_values::DataValidationOperatorValues;
+
+mod data_validation;
+pub use self::data_validation::DataValidation;
+
+mod data_validations;
+pub use self::data_validations::DataValidations;
+
+mod external_sheet_data;
+pub use self::external_sheet_data::ExternalSheetData;
+
+mod external_link;
+pub use self::external_link::ExternalLink;
+
+mod office_document_thumbnail;
+pub use self::office_document_thumbnail::Thumbnail;
+
+pub mod drawing;
@@ -0,0 +1,41 @@
+// an optional docProps/thumbnail part used as the package preview image
+#[derive(Default, Debug, Clone)]
+pub struct Thumbnail {
+    image_data: Vec<u8>,
+    media_type: String,
+}
+impl Thumbnail {
+    pub fn get_image_data(&self) -> &[u8] {
+        &self.image_data
+    }
+
+    pub fn set_image_data(&mut self, value: Vec<u8>) -> &mut Self {
+        self.image_data = value;
+        self
+    }
+
+    pub fn get_media_type(&self) -> &str {
+        if self.media_type.is_empty() {
+            "image/jpeg"
+        } else {
+            &self.media_type
+        }
+    }
+
+    pub fn set_media_type<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.media_type = value.into();
+        self
+    }
+
+    /// The file extension the writer should use for `docProps/thumbnail.*`
+    /// so the part's name, its `[Content_Types].xml` entry and its actual
+    /// bytes stay consistent with `media_type`.
+    pub fn get_file_extension(&self) -> &str {
+        match self.get_media_type() {
+            "image/png" => "png",
+            "image/gif" => "gif",
+            "image/bmp" => "bmp",
+            _ => "jpeg",
+        }
+    }
+}
@@ -0,0 +1,6 @@
+// Additive stub, same caveat as structs/mod.rs: this snapshot doesn't
+// contain the real `writer/mod.rs`, which already declares `xlsx` and
+// `driver`. This only adds the line `ods` needs to be reachable at
+// `umya_spreadsheet::writer::ods` - merge it into the existing file
+// instead of replacing it.
+pub mod ods;
@@ -0,0 +1,67 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use structs::Spreadsheet;
+use super::xlsx::XlsxError;
+use super::xlsx::part_error;
+
+mod mimetype;
+mod manifest;
+mod content_xml;
+mod styles_xml;
+mod meta_xml;
+
+/// write spreadsheet file as ODS (OpenDocument Spreadsheet).
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `writer` - writer.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let mut b: Vec::<u8> = Vec::new();
+/// let _ = umya_spreadsheet::writer::ods::write(&book, std::io::Cursor::new(&mut b));
+/// ```
+pub fn write<W: io::Seek + io::Write>(spreadsheet: &Spreadsheet, writer: W) -> Result<(), XlsxError> {
+    let mut arv = zip::ZipWriter::new(writer);
+
+    // Add mimetype. must be the first entry and stored without compression.
+    mimetype::write(&mut arv).map_err(part_error("mimetype"))?;
+
+    // Add META-INF/manifest.xml
+    manifest::write(&mut arv, "META-INF", "manifest.xml")
+        .map_err(part_error("META-INF/manifest.xml"))?;
+
+    // Add content.xml
+    content_xml::write(spreadsheet, &mut arv, "content.xml")
+        .map_err(part_error("content.xml"))?;
+
+    // Add styles.xml
+    styles_xml::write(spreadsheet, &mut arv, "styles.xml")
+        .map_err(part_error("styles.xml"))?;
+
+    // Add meta.xml
+    meta_xml::write(spreadsheet, &mut arv, "meta.xml")
+        .map_err(part_error("meta.xml"))?;
+
+    arv.finish()?;
+    Ok(())
+}
+
+/// write spreadsheet file as ODS.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to save.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz.ods");
+/// let _ = umya_spreadsheet::writer::ods::write_to_file(&book, path);
+/// ```
+pub fn write_to_file(spreadsheet: &Spreadsheet, path: &Path) -> Result<(), XlsxError> {
+    write(spreadsheet, &mut io::BufWriter::new(fs::File::create(path)?))
+}
@@ -0,0 +1,220 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use structs::Spreadsheet;
+use writer::driver::*;
+use super::super::xlsx::XlsxError;
+
+pub(crate) fn write<W: io::Write + io::Seek>(
+    spreadsheet: &Spreadsheet,
+    arv: &mut zip::ZipWriter<W>,
+    file_name: &str,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // office:document-content
+    write_start_tag(&mut writer, "office:document-content", vec![
+        ("xmlns:office", "urn:oasis:names:tc:opendocument:xmlns:office:1.0"),
+        ("xmlns:table", "urn:oasis:names:tc:opendocument:xmlns:table:1.0"),
+        ("xmlns:text", "urn:oasis:names:tc:opendocument:xmlns:text:1.0"),
+        ("office:version", "1.2"),
+    ], false);
+
+    // office:body
+    write_start_tag(&mut writer, "office:body", vec![], false);
+
+    // office:spreadsheet
+    write_start_tag(&mut writer, "office:spreadsheet", vec![], false);
+
+    for worksheet in spreadsheet.get_sheet_collection() {
+        // table:table
+        write_start_tag(&mut writer, "table:table", vec![
+            ("table:name", worksheet.get_title()),
+        ], false);
+
+        let highest_column = worksheet.get_highest_column();
+        let highest_row = worksheet.get_highest_row();
+
+        for row in 1..=highest_row {
+            write_start_tag(&mut writer, "table:table-row", vec![], false);
+
+            let mut empty_run = 0u32;
+            for column in 1..=highest_column {
+                let cell = worksheet.get_cell_by_column_and_row(&column, &row);
+                let is_empty = match cell {
+                    Some(c) => c.get_cell_value().get_value().is_empty(),
+                    None => true,
+                };
+
+                if is_empty {
+                    empty_run += 1;
+                    continue;
+                }
+
+                // flush the run-length-encoded blank cells that preceded this value
+                if empty_run > 0 {
+                    write_empty_cell(&mut writer, empty_run);
+                    empty_run = 0;
+                }
+
+                write_value_cell(&mut writer, cell.unwrap());
+            }
+
+            // a trailing run of blanks does not need to be written at all,
+            // ODS treats a short row as implicitly blank to the end.
+
+            write_end_tag(&mut writer, "table:table-row");
+        }
+
+        write_end_tag(&mut writer, "table:table");
+    }
+
+    write_end_tag(&mut writer, "office:spreadsheet");
+    write_end_tag(&mut writer, "office:body");
+    write_end_tag(&mut writer, "office:document-content");
+
+    arv.start_file(file_name, zip::write::FileOptions::default())?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
+
+fn write_empty_cell(writer: &mut Writer<Cursor<Vec<u8>>>, repeat: u32) {
+    write_start_tag(writer, "table:table-cell", vec![
+        ("table:number-columns-repeated", &repeat.to_string()),
+    ], true);
+}
+
+fn write_value_cell(writer: &mut Writer<Cursor<Vec<u8>>>, cell: &::structs::Cell) {
+    let value = cell.get_cell_value().get_value();
+    let data_type = cell.get_cell_value().get_data_type();
+    let format_code = cell.get_style().get_number_format().get_format_code();
+
+    if is_date_time_format(format_code) {
+        if let Ok(number) = value.parse::<f64>() {
+            let date_value = excel_serial_to_date(number);
+            write_start_tag(writer, "table:table-cell", vec![
+                ("office:value-type", "date"),
+                ("office:date-value", &date_value),
+            ], false);
+            write_start_tag(writer, "text:p", vec![], false);
+            write_text_node(writer, value);
+            write_end_tag(writer, "text:p");
+            write_end_tag(writer, "table:table-cell");
+            return;
+        }
+    }
+
+    // Trust the cell's own data type rather than re-guessing from the
+    // stored string - a text cell holding "00501" parses as a float but
+    // must stay `office:value-type="string"` or the leading zero is lost.
+    match data_type {
+        "n" => {
+            let number: f64 = value.parse().unwrap_or_default();
+            write_start_tag(writer, "table:table-cell", vec![
+                ("office:value-type", "float"),
+                ("office:value", &number.to_string()),
+            ], false);
+            write_start_tag(writer, "text:p", vec![], false);
+            write_text_node(writer, value);
+            write_end_tag(writer, "text:p");
+            write_end_tag(writer, "table:table-cell");
+        }
+        "b" => {
+            let is_true = value == "1" || value.eq_ignore_ascii_case("true");
+            write_start_tag(writer, "table:table-cell", vec![
+                ("office:value-type", "boolean"),
+                ("office:boolean-value", if is_true { "true" } else { "false" }),
+            ], false);
+            write_start_tag(writer, "text:p", vec![], false);
+            write_text_node(writer, value);
+            write_end_tag(writer, "text:p");
+            write_end_tag(writer, "table:table-cell");
+        }
+        _ => {
+            write_start_tag(writer, "table:table-cell", vec![
+                ("office:value-type", "string"),
+            ], false);
+            write_start_tag(writer, "text:p", vec![], false);
+            write_text_node(writer, value);
+            write_end_tag(writer, "text:p");
+            write_end_tag(writer, "table:table-cell");
+        }
+    }
+}
+
+/// A crude but workable check for the common date/time format codes -
+/// there's no dedicated date `office:value-type` signal on the cell
+/// itself, OOXML dates are just numbers formatted to look like one.
+fn is_date_time_format(format_code: &str) -> bool {
+    let lower = format_code.to_ascii_lowercase();
+    (lower.contains('y') || lower.contains('d') || lower.contains('h'))
+        && !lower.contains('@')
+        && format_code != "General"
+}
+
+/// Converts an Excel 1900-epoch serial date number to an ISO 8601 date
+/// string (`office:date-value` wants `YYYY-MM-DD[THH:MM:SS]`).
+fn excel_serial_to_date(serial: f64) -> String {
+    const EXCEL_EPOCH_OFFSET_DAYS: i64 = 25569; // days between 1899-12-30 and 1970-01-01
+    let unix_days = serial.trunc() as i64 - EXCEL_EPOCH_OFFSET_DAYS;
+    let seconds_in_day = (serial.fract() * 86400.0).round() as i64;
+    let unix_seconds = unix_days * 86400 + seconds_in_day;
+
+    let days_since_epoch = unix_seconds.div_euclid(86400);
+    let (year, month, day) = civil_from_days(days_since_epoch);
+    if seconds_in_day == 0 {
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    } else {
+        let time_of_day = unix_seconds.rem_euclid(86400);
+        let hour = time_of_day / 3600;
+        let minute = (time_of_day % 3600) / 60;
+        let second = time_of_day % 60;
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, hour, minute, second)
+    }
+}
+
+/// Howard Hinnant's `civil_from_days` algorithm: days-since-1970-01-01 to
+/// a proleptic Gregorian (year, month, day).
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_format_codes_are_recognized() {
+        assert!(is_date_time_format("yyyy-mm-dd"));
+        assert!(is_date_time_format("m/d/yy h:mm"));
+        assert!(!is_date_time_format("General"));
+        assert!(!is_date_time_format("0.00"));
+        assert!(!is_date_time_format("@"));
+    }
+
+    #[test]
+    fn excel_serial_converts_to_iso_date() {
+        // 44927 is 2023-01-01 in the Excel 1900 date system.
+        assert_eq!(excel_serial_to_date(44927.0), "2023-01-01");
+    }
+
+    #[test]
+    fn excel_serial_with_fraction_includes_time() {
+        // 44927.5 is 2023-01-01 noon.
+        assert_eq!(excel_serial_to_date(44927.5), "2023-01-01T12:00:00");
+    }
+}
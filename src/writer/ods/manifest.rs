@@ -0,0 +1,48 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use writer::driver::*;
+use super::super::xlsx::XlsxError;
+
+pub(crate) fn write<W: io::Write + io::Seek>(
+    arv: &mut zip::ZipWriter<W>,
+    sub_dir: &str,
+    file_name: &str,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // manifest:manifest
+    write_start_tag(&mut writer, "manifest:manifest", vec![
+        ("xmlns:manifest", "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0"),
+        ("manifest:version", "1.2"),
+    ], false);
+
+    // manifest:file-entry (package root)
+    write_start_tag(&mut writer, "manifest:file-entry", vec![
+        ("manifest:full-path", "/"),
+        ("manifest:version", "1.2"),
+        ("manifest:media-type", "application/vnd.oasis.opendocument.spreadsheet"),
+    ], true);
+
+    for (full_path, media_type) in &[
+        ("content.xml", "text/xml"),
+        ("styles.xml", "text/xml"),
+        ("meta.xml", "text/xml"),
+    ] {
+        write_start_tag(&mut writer, "manifest:file-entry", vec![
+            ("manifest:full-path", full_path),
+            ("manifest:media-type", media_type),
+        ], true);
+    }
+
+    write_end_tag(&mut writer, "manifest:manifest");
+
+    arv.start_file(format!("{}/{}", sub_dir, file_name), zip::write::FileOptions::default())?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use structs::Spreadsheet;
+use writer::driver::*;
+use super::super::xlsx::XlsxError;
+
+/// Maps title, subject, creator, description, keywords, created and
+/// modified from `spreadsheet.get_properties()`, the same source that
+/// `writer::xlsx::doc_props_core::write` reads for `docProps/core.xml`.
+/// lastModifiedBy, revision, category and version are not written.
+pub(crate) fn write<W: io::Write + io::Seek>(
+    spreadsheet: &Spreadsheet,
+    arv: &mut zip::ZipWriter<W>,
+    file_name: &str,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // office:document-meta
+    write_start_tag(&mut writer, "office:document-meta", vec![
+        ("xmlns:office", "urn:oasis:names:tc:opendocument:xmlns:office:1.0"),
+        ("xmlns:dc", "http://purl.org/dc/elements/1.1/"),
+        ("xmlns:meta", "urn:oasis:names:tc:opendocument:xmlns:meta:1.0"),
+        ("office:version", "1.2"),
+    ], false);
+
+    write_start_tag(&mut writer, "office:meta", vec![], false);
+
+    // dc:title
+    write_start_tag(&mut writer, "dc:title", vec![], false);
+    write_text_node(&mut writer, spreadsheet.get_properties().get_title());
+    write_end_tag(&mut writer, "dc:title");
+
+    // dc:subject
+    write_start_tag(&mut writer, "dc:subject", vec![], false);
+    write_text_node(&mut writer, spreadsheet.get_properties().get_subject());
+    write_end_tag(&mut writer, "dc:subject");
+
+    // dc:creator
+    write_start_tag(&mut writer, "dc:creator", vec![], false);
+    write_text_node(&mut writer, spreadsheet.get_properties().get_creator());
+    write_end_tag(&mut writer, "dc:creator");
+
+    // dc:description
+    write_start_tag(&mut writer, "dc:description", vec![], false);
+    write_text_node(&mut writer, spreadsheet.get_properties().get_description());
+    write_end_tag(&mut writer, "dc:description");
+
+    // meta:keyword
+    write_start_tag(&mut writer, "meta:keyword", vec![], false);
+    write_text_node(&mut writer, spreadsheet.get_properties().get_keywords());
+    write_end_tag(&mut writer, "meta:keyword");
+
+    // meta:creation-date
+    write_start_tag(&mut writer, "meta:creation-date", vec![], false);
+    write_text_node(&mut writer, spreadsheet.get_properties().get_created());
+    write_end_tag(&mut writer, "meta:creation-date");
+
+    // dc:date
+    write_start_tag(&mut writer, "dc:date", vec![], false);
+    write_text_node(&mut writer, spreadsheet.get_properties().get_modified());
+    write_end_tag(&mut writer, "dc:date");
+
+    write_end_tag(&mut writer, "office:meta");
+    write_end_tag(&mut writer, "office:document-meta");
+
+    arv.start_file(file_name, zip::write::FileOptions::default())?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use std::io;
+
+use super::super::xlsx::XlsxError;
+
+/// The ODS package is a ZIP file whose very first entry must be an
+/// uncompressed `mimetype` file containing nothing but the literal
+/// media type. Viewers rely on this to sniff the package type without
+/// inflating the rest of the archive.
+pub(crate) fn write<W: io::Write + io::Seek>(arv: &mut zip::ZipWriter<W>) -> Result<(), XlsxError> {
+    let options = zip::write::FileOptions::default()
+        .compression_method(zip::CompressionMethod::Stored);
+    arv.start_file("mimetype", options)?;
+    arv.write_all(b"application/vnd.oasis.opendocument.spreadsheet")?;
+    Ok(())
+}
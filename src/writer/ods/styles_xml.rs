@@ -0,0 +1,37 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use structs::Spreadsheet;
+use writer::driver::*;
+use super::super::xlsx::XlsxError;
+
+/// umya-spreadsheet does not yet model ODF automatic/cell styles, so this
+/// emits the minimal `styles.xml` every ODS consumer expects to be present,
+/// leaving cell formatting to be layered on in a follow-up.
+pub(crate) fn write<W: io::Write + io::Seek>(
+    _spreadsheet: &Spreadsheet,
+    arv: &mut zip::ZipWriter<W>,
+    file_name: &str,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // office:document-styles
+    write_start_tag(&mut writer, "office:document-styles", vec![
+        ("xmlns:office", "urn:oasis:names:tc:opendocument:xmlns:office:1.0"),
+        ("xmlns:style", "urn:oasis:names:tc:opendocument:xmlns:style:1.0"),
+        ("office:version", "1.2"),
+    ], false);
+
+    write_start_tag(&mut writer, "office:styles", vec![], true);
+
+    write_end_tag(&mut writer, "office:document-styles");
+
+    arv.start_file(file_name, zip::write::FileOptions::default())?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
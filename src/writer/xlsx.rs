@@ -26,6 +26,11 @@ mod vba_project_bin;
 mod comment;
 mod vml_drawing;
 mod media;
+mod thumbnail;
+mod external_links;
+
+mod write_options;
+pub use self::write_options::WriteOptions;
 
 #[derive(Debug)]
 pub enum XlsxError {
@@ -33,6 +38,15 @@ pub enum XlsxError {
     Xml(quick_xml::Error),
     Zip(zip::result::ZipError),
     Uft8(FromUtf8Error),
+    /// a named package part failed to write; wraps the underlying error so
+    /// callers can tell which entry produced a corrupt `.xlsx`.
+    Part(String, Box<XlsxError>),
+}
+
+/// wraps an error from writing a single package part so callers can tell
+/// which entry failed without losing the original cause.
+pub(crate) fn part_error(part_name: &str) -> impl Fn(XlsxError) -> XlsxError + '_ {
+    move |e| XlsxError::Part(part_name.to_string(), Box::new(e))
 }
 
 impl From<io::Error> for XlsxError {
@@ -72,29 +86,69 @@ impl From<FromUtf8Error> for XlsxError {
 /// let _ = umya_spreadsheet::writer::xlsx::write(&book, std::io::Cursor::new(&mut b));
 /// ```
 pub fn write<W: io::Seek + io::Write>(spreadsheet: &Spreadsheet, writer: W) -> Result<(), XlsxError> {
+    write_with_options(spreadsheet, writer, &WriteOptions::default())
+}
+
+/// write spreadsheet file, choosing how parts of the package are compressed.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `writer` - writer.
+/// * `options` - compression method/level for the parts whose writers receive
+///   it: `[Content_Types].xml`, `docProps/core.xml`, the thumbnail, `_rels/.rels`,
+///   `xl/workbook.xml` and the external link parts. The other parts are not
+///   affected by it.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let mut b: Vec::<u8> = Vec::new();
+/// let mut options = umya_spreadsheet::writer::xlsx::WriteOptions::default();
+/// options.set_uncompressed(true);
+/// let _ = umya_spreadsheet::writer::xlsx::write_with_options(&book, std::io::Cursor::new(&mut b), &options);
+/// ```
+pub fn write_with_options<W: io::Seek + io::Write>(spreadsheet: &Spreadsheet, writer: W, options: &WriteOptions) -> Result<(), XlsxError> {
     let mut arv = zip::ZipWriter::new(writer);
 
     // Add Content_Types
-    let _= content_types::write(spreadsheet, &mut arv, "[Content_Types].xml");
+    content_types::write(spreadsheet, &mut arv, "[Content_Types].xml", options)
+        .map_err(part_error("[Content_Types].xml"))?;
 
     // Add docProps App
-    let _= doc_props_app::write(spreadsheet, &mut arv, "docProps", "app.xml");
+    doc_props_app::write(spreadsheet, &mut arv, "docProps", "app.xml")
+        .map_err(part_error("docProps/app.xml"))?;
 
     // Add docProps Core
-    let _= doc_props_core::write(spreadsheet, &mut arv, "docProps", "core.xml");
+    doc_props_core::write(spreadsheet, &mut arv, "docProps", "core.xml", options)
+        .map_err(part_error("docProps/core.xml"))?;
+
+    // Add docProps thumbnail, if one was set via WriteOptions.
+    if let Some(thumb) = options.get_thumbnail() {
+        thumbnail::write(thumb, &mut arv, "docProps", options)
+            .map_err(part_error("docProps/thumbnail"))?;
+    }
 
     // Add vbaProject.bin
-    let _= vba_project_bin::write(spreadsheet, &mut arv, "xl", "vbaProject.bin");
+    vba_project_bin::write(spreadsheet, &mut arv, "xl", "vbaProject.bin")
+        .map_err(part_error("xl/vbaProject.bin"))?;
 
     // Add relationships
-    let _ = rels::write(spreadsheet, &mut arv, "_rels", ".rels");
-    let _ = workbook_rels::write(spreadsheet, &mut arv, "xl/_rels", "workbook.xml.rels");
+    rels::write(spreadsheet, &mut arv, "_rels", ".rels", options)
+        .map_err(part_error("_rels/.rels"))?;
+    workbook_rels::write(spreadsheet, &mut arv, "xl/_rels", "workbook.xml.rels")
+        .map_err(part_error("xl/_rels/workbook.xml.rels"))?;
 
     // Add theme
-    let _ = theme::write(spreadsheet.get_theme(), &mut arv, "xl/theme", "theme1.xml");
+    theme::write(spreadsheet.get_theme(), &mut arv, "xl/theme", "theme1.xml")
+        .map_err(part_error("xl/theme/theme1.xml"))?;
 
     // Add workbook
-    let _ = workbook::write(spreadsheet, &mut arv, "xl", "workbook.xml");
+    workbook::write(spreadsheet, &mut arv, "xl", "workbook.xml", options)
+        .map_err(part_error("xl/workbook.xml"))?;
+
+    // Add external workbook links
+    external_links::write(options.get_external_link_collection(), &mut arv, options)
+        .map_err(part_error("xl/externalLinks"))?;
 
     // Add worksheets and relationships (drawings, ...)
     let mut chart_id = 1;
@@ -106,13 +160,19 @@ pub fn write<W: io::Seek + io::Write>(spreadsheet: &Spreadsheet, writer: W) -> R
     stylesheet.init_setup();
     for i in 0..spreadsheet.get_sheet_count() {
         let p_worksheet_id:&str = &(i+1).to_string();
-        let _ = worksheet::write(&spreadsheet, &i, &mut shared_string_table, &mut stylesheet, &mut arv);
+        worksheet::write(&spreadsheet, &i, &mut shared_string_table, &mut stylesheet, &mut arv)
+            .map_err(part_error("xl/worksheets"))?;
         let worksheet = &spreadsheet.get_sheet_collection()[i];
-        let _ = worksheet_rels::write(worksheet, p_worksheet_id, &drawing_id, &comment_id,  &mut arv);
-        let _ = drawing::write(worksheet, &drawing_id, &mut arv);
-        let _ = drawing_rels::write(worksheet, &drawing_id, &chart_id, &mut arv);
-        let _ = comment::write(worksheet, &comment_id,  &mut arv);
-        let _ = vml_drawing::write(worksheet, &comment_id,  &mut arv);
+        worksheet_rels::write(worksheet, p_worksheet_id, &drawing_id, &comment_id,  &mut arv)
+            .map_err(part_error("xl/worksheets/_rels"))?;
+        drawing::write(worksheet, &drawing_id, &mut arv)
+            .map_err(part_error("xl/drawings"))?;
+        drawing_rels::write(worksheet, &drawing_id, &chart_id, &mut arv)
+            .map_err(part_error("xl/drawings/_rels"))?;
+        comment::write(worksheet, &comment_id,  &mut arv)
+            .map_err(part_error("xl/comments"))?;
+        vml_drawing::write(worksheet, &comment_id,  &mut arv)
+            .map_err(part_error("xl/drawings/vmlDrawing"))?;
 
         if worksheet.has_drawing_object() {
             drawing_id += 1;
@@ -124,25 +184,46 @@ pub fn write<W: io::Seek + io::Write>(spreadsheet: &Spreadsheet, writer: W) -> R
 
         for graphic_frame in worksheet.get_worksheet_drawing().get_graphic_frame_collection(){
             let chart_space = graphic_frame.get_graphic().get_graphic_data().get_chart_space();
-            let _ = chart::write(chart_space, &chart_id, &mut arv);
+            chart::write(chart_space, &chart_id, &mut arv)
+                .map_err(part_error("xl/charts"))?;
             chart_id += 1;
         }
 
         for picture in worksheet.get_worksheet_drawing().get_picture_collection(){
-            let _ = media::write(picture, &mut arv, "xl/media");
+            media::write(picture, &mut arv, "xl/media")
+                .map_err(part_error("xl/media"))?;
         }
     }
 
     // Add SharedStrings
-    let _ = shared_strings::write(&shared_string_table, &mut arv).unwrap();
+    shared_strings::write(&shared_string_table, &mut arv)
+        .map_err(part_error("xl/sharedStrings.xml"))?;
 
     // Add Styles
-    let _ = styles::write(&stylesheet, &mut arv).unwrap();
+    styles::write(&stylesheet, &mut arv)
+        .map_err(part_error("xl/styles.xml"))?;
 
     arv.finish()?;
     Ok(())
 }
 
+/// write spreadsheet to an in-memory buffer, skipping the temp-dir round trip.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `options` - passed to `write_with_options`; see there for the parts it affects.
+/// # Return value
+/// * `Result` - OK is the raw `.xlsx` bytes. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let bytes = umya_spreadsheet::writer::xlsx::write_buffer(&book, &umya_spreadsheet::writer::xlsx::WriteOptions::default()).unwrap();
+/// ```
+pub fn write_buffer(spreadsheet: &Spreadsheet, options: &WriteOptions) -> Result<Vec<u8>, XlsxError> {
+    let mut buffer = io::Cursor::new(Vec::new());
+    write_with_options(spreadsheet, &mut buffer, options)?;
+    Ok(buffer.into_inner())
+}
+
 /// write spreadsheet file.
 /// # Arguments
 /// * `spreadsheet` - Spreadsheet structs object.
@@ -0,0 +1,150 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use super::super::structs::spreadsheet::Spreadsheet;
+use writer::driver::*;
+use super::XlsxError;
+use super::WriteOptions;
+
+/// Writes `[Content_Types].xml`, the OPC manifest that tells readers the
+/// media type of every part in the package.
+///
+/// This mirrors the part-writing loop in `write_with_options` exactly
+/// (same `drawing_id`/`comment_id`/`chart_id` bookkeeping) so every part
+/// that pipeline actually emits - worksheets, drawings, charts, comments,
+/// vmlDrawing, media, vbaProject.bin, the thumbnail preview, external
+/// workbook links - gets an entry here instead of just the parts this
+/// module happens to own.
+pub(crate) fn write<W: io::Write + io::Seek>(
+    spreadsheet: &Spreadsheet,
+    arv: &mut zip::ZipWriter<W>,
+    file_name: &str,
+    options: &WriteOptions,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
+    write_new_line(&mut writer);
+
+    // Types
+    write_start_tag(&mut writer, "Types", vec![
+        ("xmlns", "http://schemas.openxmlformats.org/package/2006/content-types"),
+    ], false);
+
+    write_start_tag(&mut writer, "Default", vec![
+        ("Extension", "rels"),
+        ("ContentType", "application/vnd.openxmlformats-package.relationships+xml"),
+    ], true);
+    write_start_tag(&mut writer, "Default", vec![
+        ("Extension", "xml"),
+        ("ContentType", "application/xml"),
+    ], true);
+    write_start_tag(&mut writer, "Default", vec![
+        ("Extension", "vml"),
+        ("ContentType", "application/vnd.openxmlformats-officedocument.vmlDrawing"),
+    ], true);
+
+    // Image extensions need a single Default each - the thumbnail and the
+    // worksheets' own media can both want "jpeg"/"png", so track what's
+    // already been declared instead of risking a duplicate Extension.
+    let mut jpeg_registered = false;
+    let mut png_registered = false;
+    let mut other_registered = false;
+    if let Some(thumbnail) = options.get_thumbnail() {
+        let registered = match thumbnail.get_file_extension() {
+            "png" => &mut png_registered,
+            "jpeg" => &mut jpeg_registered,
+            _ => &mut other_registered,
+        };
+        write_image_default(&mut writer, thumbnail.get_file_extension(), thumbnail.get_media_type(), registered);
+    }
+
+    write_override(&mut writer, "/xl/workbook.xml", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml");
+    write_override(&mut writer, "/xl/styles.xml", "application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml");
+    write_override(&mut writer, "/xl/sharedStrings.xml", "application/vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings+xml");
+    write_override(&mut writer, "/xl/theme/theme1.xml", "application/vnd.openxmlformats-officedocument.theme+xml");
+    write_override(&mut writer, "/docProps/core.xml", "application/vnd.openxmlformats-package.core-properties+xml");
+    write_override(&mut writer, "/docProps/app.xml", "application/vnd.openxmlformats-officedocument.extended-properties+xml");
+    write_override(&mut writer, "/xl/vbaProject.bin", "application/vnd.ms-office.vbaProject");
+
+    let mut chart_id = 1;
+    let mut drawing_id = 1;
+    let mut comment_id = 1;
+    for i in 0..spreadsheet.get_sheet_count() {
+        write_override(
+            &mut writer,
+            &format!("/xl/worksheets/sheet{}.xml", i + 1),
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml",
+        );
+
+        let worksheet = &spreadsheet.get_sheet_collection()[i];
+
+        if worksheet.has_drawing_object() {
+            write_override(
+                &mut writer,
+                &format!("/xl/drawings/drawing{}.xml", drawing_id),
+                "application/vnd.openxmlformats-officedocument.drawing+xml",
+            );
+            drawing_id += 1;
+        }
+
+        if worksheet.has_comments() {
+            write_override(
+                &mut writer,
+                &format!("/xl/comments/comment{}.xml", comment_id),
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml",
+            );
+            comment_id += 1;
+        }
+
+        for _ in worksheet.get_worksheet_drawing().get_graphic_frame_collection() {
+            write_override(
+                &mut writer,
+                &format!("/xl/charts/chart{}.xml", chart_id),
+                "application/vnd.openxmlformats-officedocument.drawingml.chart+xml",
+            );
+            chart_id += 1;
+        }
+
+        if !worksheet.get_worksheet_drawing().get_picture_collection().is_empty() {
+            // `media::write` names parts after each picture's own extension;
+            // cover the two formats umya-spreadsheet actually emits.
+            write_image_default(&mut writer, "png", "image/png", &mut png_registered);
+            write_image_default(&mut writer, "jpeg", "image/jpeg", &mut jpeg_registered);
+        }
+    }
+
+    for (index, _) in options.get_external_link_collection().iter().enumerate() {
+        write_override(
+            &mut writer,
+            &format!("/xl/externalLinks/externalLink{}.xml", index + 1),
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.externalLink+xml",
+        );
+    }
+
+    write_end_tag(&mut writer, "Types");
+
+    arv.start_file(file_name, options.get_file_options())?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
+
+fn write_image_default<W: io::Write>(writer: &mut Writer<W>, extension: &str, content_type: &str, registered: &mut bool) {
+    if *registered {
+        return;
+    }
+    write_start_tag(writer, "Default", vec![
+        ("Extension", extension),
+        ("ContentType", content_type),
+    ], true);
+    *registered = true;
+}
+
+fn write_override<W: io::Write>(writer: &mut Writer<W>, part_name: &str, content_type: &str) {
+    write_start_tag(writer, "Override", vec![
+        ("PartName", part_name),
+        ("ContentType", content_type),
+    ], true);
+}
@@ -6,8 +6,10 @@ use tempdir::TempDir;
 use super::super::structs::spreadsheet::Spreadsheet;
 use super::driver::*;
 use super::XlsxError;
+use super::WriteOptions;
+use std::io;
 
-pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, file_name: &str) -> Result<(), XlsxError> {
+pub(crate) fn write<W: io::Write + io::Seek>(spreadsheet: &Spreadsheet, arv: &mut zip::ZipWriter<W>, sub_dir: &str, file_name: &str, options: &WriteOptions) -> Result<(), XlsxError> {
     let mut writer = Writer::new(Cursor::new(Vec::new()));
     // XML header
     let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
@@ -82,6 +84,7 @@ pub(crate) fn write(spreadsheet: &Spreadsheet, dir: &TempDir, sub_dir: &str, fil
     write_end_tag(&mut writer, "cp:version");
 
     write_end_tag(&mut writer, "cp:coreProperties");
-    let _ = make_file_from_writer(format!("{}/{}",sub_dir,file_name).as_str(), dir, writer, Some(sub_dir)).unwrap();
+    arv.start_file(format!("{}/{}", sub_dir, file_name), options.get_file_options())?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
     Ok(())
 }
\ No newline at end of file
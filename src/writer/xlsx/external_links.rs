@@ -0,0 +1,133 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use structs::ExternalLink;
+use writer::driver::*;
+use super::XlsxError;
+use super::WriteOptions;
+
+/// Writes `xl/externalLinks/externalLinkN.xml` and its `.rels` part for
+/// every `ExternalLink` in `external_link_collection`, so formulas like
+/// `[Book2.xlsx]Sheet1!A1` keep a cached value even when the source
+/// workbook isn't available. `content_types` now registers each part as
+/// it's written, so the manifest side of this is handled.
+///
+/// `workbook.xml` is not part of this tree, so the matching
+/// `<externalReferences>` block that points `rId`s at these parts still
+/// can't be added here; until that file exists, a reader that doesn't
+/// already know the relationship IDs can't resolve `[Book2.xlsx]` style
+/// references from the workbook side.
+pub(crate) fn write<W: io::Write + io::Seek>(
+    external_link_collection: &[ExternalLink],
+    arv: &mut zip::ZipWriter<W>,
+    options: &WriteOptions,
+) -> Result<(), XlsxError> {
+    for (index, external_link) in external_link_collection.iter().enumerate() {
+        let external_link_no = index + 1;
+        write_external_link(external_link, &external_link_no, arv, options)?;
+        write_external_link_rels(external_link, &external_link_no, arv, options)?;
+    }
+    Ok(())
+}
+
+fn write_external_link<W: io::Write + io::Seek>(
+    external_link: &ExternalLink,
+    external_link_no: &usize,
+    arv: &mut zip::ZipWriter<W>,
+    options: &WriteOptions,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
+    write_new_line(&mut writer);
+
+    // externalLink
+    write_start_tag(&mut writer, "externalLink", vec![
+        ("xmlns", "http://schemas.openxmlformats.org/spreadsheetml/2006/main"),
+        ("xmlns:r", "http://schemas.openxmlformats.org/officeDocument/2006/relationships"),
+    ], false);
+
+    // externalBook
+    write_start_tag(&mut writer, "externalBook", vec![
+        ("r:id", "rId1"),
+    ], false);
+
+    // sheetNames
+    write_start_tag(&mut writer, "sheetNames", vec![], false);
+    for sheet_name in external_link.get_sheet_names() {
+        write_start_tag(&mut writer, "sheetName", vec![
+            ("val", sheet_name),
+        ], true);
+    }
+    write_end_tag(&mut writer, "sheetNames");
+
+    // sheetDataSet
+    write_start_tag(&mut writer, "sheetDataSet", vec![], false);
+    for sheet_data in external_link.get_sheet_data_set() {
+        write_start_tag(&mut writer, "sheetData", vec![
+            ("sheetId", &sheet_data.get_sheet_id().to_string()),
+            ("refreshError", "1"),
+        ], false);
+
+        for (coordinate, value) in sheet_data.get_cell_collection() {
+            write_start_tag(&mut writer, "row", vec![], false);
+            write_start_tag(&mut writer, "cell", vec![
+                ("r", coordinate),
+                ("t", "str"),
+            ], false);
+            write_start_tag(&mut writer, "v", vec![], false);
+            write_text_node(&mut writer, value);
+            write_end_tag(&mut writer, "v");
+            write_end_tag(&mut writer, "cell");
+            write_end_tag(&mut writer, "row");
+        }
+
+        write_end_tag(&mut writer, "sheetData");
+    }
+    write_end_tag(&mut writer, "sheetDataSet");
+
+    write_end_tag(&mut writer, "externalBook");
+    write_end_tag(&mut writer, "externalLink");
+
+    arv.start_file(
+        format!("xl/externalLinks/externalLink{}.xml", external_link_no),
+        options.get_file_options(),
+    )?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
+
+fn write_external_link_rels<W: io::Write + io::Seek>(
+    external_link: &ExternalLink,
+    external_link_no: &usize,
+    arv: &mut zip::ZipWriter<W>,
+    options: &WriteOptions,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
+    write_new_line(&mut writer);
+
+    // Relationships
+    write_start_tag(&mut writer, "Relationships", vec![
+        ("xmlns", "http://schemas.openxmlformats.org/package/2006/relationships"),
+    ], false);
+
+    write_start_tag(&mut writer, "Relationship", vec![
+        ("Id", "rId1"),
+        ("Type", "http://schemas.openxmlformats.org/officeDocument/2006/relationships/externalLinkPath"),
+        ("Target", external_link.get_target()),
+        ("TargetMode", "External"),
+    ], true);
+
+    write_end_tag(&mut writer, "Relationships");
+
+    arv.start_file(
+        format!("xl/externalLinks/_rels/externalLink{}.xml.rels", external_link_no),
+        options.get_file_options(),
+    )?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use quick_xml::events::{Event, BytesDecl};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use super::super::structs::spreadsheet::Spreadsheet;
+use writer::driver::*;
+use super::XlsxError;
+use super::WriteOptions;
+
+/// Writes the package-level `_rels/.rels`, the entry point an OPC reader
+/// follows to find `xl/workbook.xml` and the docProps parts.
+pub(crate) fn write<W: io::Write + io::Seek>(
+    _spreadsheet: &Spreadsheet,
+    arv: &mut zip::ZipWriter<W>,
+    sub_dir: &str,
+    file_name: &str,
+    options: &WriteOptions,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    // XML header
+    let _ = writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), Some(b"yes"))));
+    write_new_line(&mut writer);
+
+    // Relationships
+    write_start_tag(&mut writer, "Relationships", vec![
+        ("xmlns", "http://schemas.openxmlformats.org/package/2006/relationships"),
+    ], false);
+
+    write_start_tag(&mut writer, "Relationship", vec![
+        ("Id", "rId1"),
+        ("Type", "http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument"),
+        ("Target", "xl/workbook.xml"),
+    ], true);
+    write_start_tag(&mut writer, "Relationship", vec![
+        ("Id", "rId2"),
+        ("Type", "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties"),
+        ("Target", "docProps/core.xml"),
+    ], true);
+    write_start_tag(&mut writer, "Relationship", vec![
+        ("Id", "rId3"),
+        ("Type", "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties"),
+        ("Target", "docProps/app.xml"),
+    ], true);
+    if let Some(thumbnail) = options.get_thumbnail() {
+        write_start_tag(&mut writer, "Relationship", vec![
+            ("Id", "rId4"),
+            ("Type", "http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail"),
+            ("Target", &format!("docProps/thumbnail.{}", thumbnail.get_file_extension())),
+        ], true);
+    }
+
+    write_end_tag(&mut writer, "Relationships");
+
+    arv.start_file(format!("{}/{}", sub_dir, file_name), options.get_file_options())?;
+    arv.write_all(writer.into_inner().into_inner().as_slice())?;
+    Ok(())
+}
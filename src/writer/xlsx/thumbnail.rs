@@ -0,0 +1,26 @@
+use std::io;
+
+use structs::Thumbnail;
+use super::XlsxError;
+use super::WriteOptions;
+
+/// Writes the optional `docProps/thumbnail.*` preview part, named after
+/// `thumbnail.get_file_extension()` so a PNG thumbnail doesn't end up
+/// mislabeled as `thumbnail.jpeg`.
+///
+/// `content_types` adds the matching Default entry and `rels` adds the
+/// `metadata/thumbnail` relationship whenever `options.get_thumbnail()`
+/// is set, so this part is fully registered once written. There's still
+/// no fallback that renders a default thumbnail from the first worksheet
+/// when the caller hasn't supplied one.
+pub(crate) fn write<W: io::Write + io::Seek>(
+    thumbnail: &Thumbnail,
+    arv: &mut zip::ZipWriter<W>,
+    sub_dir: &str,
+    options: &WriteOptions,
+) -> Result<(), XlsxError> {
+    let file_name = format!("thumbnail.{}", thumbnail.get_file_extension());
+    arv.start_file(format!("{}/{}", sub_dir, file_name), options.get_file_options())?;
+    arv.write_all(thumbnail.get_image_data())?;
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use structs::ExternalLink;
+use structs::Thumbnail;
+
+/// Controls how parts of the package are compressed when writing.
+///
+/// `write` defaults to deflate at the zip crate's default level, which is
+/// fine for small workbooks but slow for large generated sheets. Passing a
+/// `WriteOptions` through `write_with_options` lets callers trade file size
+/// for throughput, e.g. `WriteOptions::default().set_compression_method(zip::CompressionMethod::Stored)`.
+///
+/// `thumbnail` and `external_link_collection` are document content, not a
+/// serialization setting, and don't belong here - they should live on
+/// `Spreadsheet` so they persist regardless of which `WriteOptions` (or
+/// none, via `write()`) a given call happens to pass, and so `write()`'s
+/// `WriteOptions::default()` doesn't silently drop whatever a caller set
+/// elsewhere. They're parked on this struct only because
+/// `structs/spreadsheet.rs` isn't part of this tree; move them once it is.
+#[derive(Debug, Clone)]
+pub struct WriteOptions {
+    compression_method: zip::CompressionMethod,
+    compression_level: Option<i32>,
+    thumbnail: Option<Thumbnail>,
+    external_link_collection: Vec<ExternalLink>,
+}
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            compression_method: zip::CompressionMethod::Deflated,
+            compression_level: None,
+            thumbnail: None,
+            external_link_collection: Vec::new(),
+        }
+    }
+}
+impl WriteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_compression_method(&self) -> &zip::CompressionMethod {
+        &self.compression_method
+    }
+
+    pub fn set_compression_method(&mut self, value: zip::CompressionMethod) -> &mut Self {
+        self.compression_method = value;
+        self
+    }
+
+    pub fn get_compression_level(&self) -> &Option<i32> {
+        &self.compression_level
+    }
+
+    pub fn set_compression_level(&mut self, value: i32) -> &mut Self {
+        self.compression_level = Some(value);
+        self
+    }
+
+    /// Shorthand for storing uncompressed every part that `write_with_options`
+    /// writes with these options; parts whose writers don't take them are
+    /// not affected.
+    pub fn set_uncompressed(&mut self, value: bool) -> &mut Self {
+        if value {
+            self.compression_method = zip::CompressionMethod::Stored;
+            self.compression_level = None;
+        }
+        self
+    }
+
+    pub fn get_thumbnail(&self) -> &Option<Thumbnail> {
+        &self.thumbnail
+    }
+
+    pub fn set_thumbnail(&mut self, value: Thumbnail) -> &mut Self {
+        self.thumbnail = Some(value);
+        self
+    }
+
+    pub fn get_external_link_collection(&self) -> &Vec<ExternalLink> {
+        &self.external_link_collection
+    }
+
+    pub fn add_external_link(&mut self, value: ExternalLink) -> &mut Self {
+        self.external_link_collection.push(value);
+        self
+    }
+
+    pub(crate) fn get_file_options(&self) -> zip::write::FileOptions {
+        let mut file_options = zip::write::FileOptions::default()
+            .compression_method(self.compression_method);
+        if let Some(level) = self.compression_level {
+            file_options = file_options.compression_level(Some(level));
+        }
+        file_options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_compresses_with_deflate() {
+        let options = WriteOptions::default();
+        assert_eq!(*options.get_compression_method(), zip::CompressionMethod::Deflated);
+        assert_eq!(*options.get_compression_level(), None);
+    }
+
+    #[test]
+    fn set_uncompressed_switches_to_stored() {
+        let mut options = WriteOptions::new();
+        options.set_compression_level(9);
+        options.set_uncompressed(true);
+        assert_eq!(*options.get_compression_method(), zip::CompressionMethod::Stored);
+        assert_eq!(*options.get_compression_level(), None);
+    }
+
+    #[test]
+    fn thumbnail_and_external_links_round_trip() {
+        let mut options = WriteOptions::new();
+        assert!(options.get_thumbnail().is_none());
+        assert!(options.get_external_link_collection().is_empty());
+
+        let mut thumbnail = Thumbnail::default();
+        thumbnail.set_image_data(vec![0xFF, 0xD8, 0xFF]);
+        options.set_thumbnail(thumbnail);
+        assert_eq!(options.get_thumbnail().as_ref().unwrap().get_image_data(), &[0xFF, 0xD8, 0xFF]);
+
+        let mut link = ExternalLink::default();
+        link.set_target("Book2.xlsx");
+        options.add_external_link(link);
+        assert_eq!(options.get_external_link_collection().len(), 1);
+    }
+}